@@ -0,0 +1,213 @@
+//! Style your widgets.
+use crate::Length;
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use dodrio::bumpalo;
+
+/// A CSS rule of a VDOM node.
+#[derive(Debug)]
+pub enum Rule {
+    /// Container with vertical distribution
+    Column,
+
+    /// Container with horizontal distribution
+    Row,
+
+    /// Padding of the container
+    Padding(u16),
+
+    /// Spacing between elements
+    Spacing(u16),
+
+    /// Image-based indicator for a toggle widget (checkbox or radio).
+    ///
+    /// The native indicator is hidden and replaced by a `<span>` whose
+    /// background image swaps between the given URLs based on the `:checked`
+    /// and `:hover` pseudo-classes.
+    Toggler(Toggler),
+}
+
+/// The set of image URLs used to draw a themed toggle indicator.
+#[derive(Debug, Clone, Hash)]
+pub struct Toggler {
+    /// The indicator shown when the widget is unchecked.
+    pub unchecked: String,
+
+    /// The indicator shown when the widget is checked.
+    pub checked: String,
+
+    /// The indicator shown when hovering an unchecked widget.
+    pub hover_unchecked: String,
+
+    /// The indicator shown when hovering a checked widget.
+    pub hover_checked: String,
+}
+
+impl Rule {
+    /// Returns the class name of the [`Rule`].
+    ///
+    /// [`Rule`]: enum.Rule.html
+    pub fn class<'a>(&self) -> String {
+        match self {
+            Rule::Column => String::from("c"),
+            Rule::Row => String::from("r"),
+            Rule::Padding(padding) => format!("p-{}", padding),
+            Rule::Spacing(spacing) => format!("s-{}", spacing),
+            Rule::Toggler(toggler) => {
+                let mut hasher = DefaultHasher::new();
+                toggler.hash(&mut hasher);
+
+                format!("t-{:x}", hasher.finish())
+            }
+        }
+    }
+
+    /// Returns the declaration of the [`Rule`].
+    ///
+    /// [`Rule`]: enum.Rule.html
+    pub fn declaration<'a>(&self, bump: &'a bumpalo::Bump) -> &'a str {
+        let class = self.class();
+
+        match self {
+            Rule::Column => {
+                let body = "{ display: flex; flex-direction: column; }";
+
+                bumpalo::format!(in bump, ".{} {}", class, body).into_bump_str()
+            }
+            Rule::Row => {
+                let body = "{ display: flex; flex-direction: row; }";
+
+                bumpalo::format!(in bump, ".{} {}", class, body).into_bump_str()
+            }
+            Rule::Padding(padding) => bumpalo::format!(
+                in bump,
+                ".{} {{ box-sizing: border-box; padding: {}px }}",
+                class,
+                padding
+            )
+            .into_bump_str(),
+            Rule::Spacing(spacing) => bumpalo::format!(
+                in bump,
+                ".c.{} > * {{ margin-bottom: {}px }} \
+                 .r.{} > * {{ margin-right: {}px }} \
+                 .c.{} > *:last-child {{ margin-bottom: 0 }} \
+                 .r.{} > *:last-child {{ margin-right: 0 }}",
+                class,
+                spacing,
+                class,
+                spacing,
+                class,
+                class
+            )
+            .into_bump_str(),
+            Rule::Toggler(toggler) => bumpalo::format!(
+                in bump,
+                ".{class} input {{ position: absolute; opacity: 0; }} \
+                 .{class} .indicator {{ display: inline-block; width: 20px; height: 20px; background-size: contain; background-repeat: no-repeat; background-image: url('{unchecked}'); }} \
+                 .{class} input:checked + .indicator {{ background-image: url('{checked}'); }} \
+                 .{class}:hover .indicator {{ background-image: url('{hover_unchecked}'); }} \
+                 .{class}:hover input:checked + .indicator {{ background-image: url('{hover_checked}'); }}",
+                class = class,
+                unchecked = escape_url(&toggler.unchecked),
+                checked = escape_url(&toggler.checked),
+                hover_unchecked = escape_url(&toggler.hover_unchecked),
+                hover_checked = escape_url(&toggler.hover_checked)
+            )
+            .into_bump_str(),
+        }
+    }
+}
+
+/// Escapes a URL so it can be safely embedded inside a quoted CSS `url('...')`.
+///
+/// A literal quote or backslash would otherwise let the value break out of the
+/// declaration, so they are backslash-escaped and newlines are dropped.
+fn escape_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+
+    for c in url.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\'' => escaped.push_str("\\'"),
+            '\n' | '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// A cascading style sheet.
+#[derive(Debug)]
+pub struct Css<'a> {
+    rules: BTreeMap<String, &'a str>,
+}
+
+impl<'a> Css<'a> {
+    /// Creates an empty [`Css`].
+    ///
+    /// [`Css`]: struct.Css.html
+    pub fn new() -> Self {
+        Css {
+            rules: BTreeMap::new(),
+        }
+    }
+
+    /// Inserts the [`Rule`] in the [`Css`], if it was not previously inserted.
+    ///
+    /// It returns the class name of the provided [`Rule`].
+    ///
+    /// [`Rule`]: enum.Rule.html
+    /// [`Css`]: struct.Css.html
+    pub fn insert(&mut self, bump: &'a bumpalo::Bump, rule: Rule) -> String {
+        let class = rule.class();
+
+        if !self.rules.contains_key(&class) {
+            let _ = self.rules.insert(class.clone(), rule.declaration(bump));
+        }
+
+        class
+    }
+
+    /// Produces the VDOM node of the [`Css`].
+    ///
+    /// [`Css`]: struct.Css.html
+    pub fn node(self, bump: &'a bumpalo::Bump) -> dodrio::Node<'a> {
+        use dodrio::builder::*;
+
+        let mut declarations = bumpalo::collections::Vec::new_in(bump);
+
+        declarations.push(text("html { height: 100% }"));
+        declarations.push(text(
+            "body { height: 100%; margin: 0; padding: 0; font-family: sans-serif }",
+        ));
+        declarations.push(text("* { margin: 0; padding: 0 }"));
+        declarations.push(text("button { cursor: pointer; }"));
+
+        for declaration in self.rules.values() {
+            declarations.push(text(*declaration));
+        }
+
+        style(bump).children(declarations).finish()
+    }
+}
+
+impl<'a> Default for Css<'a> {
+    fn default() -> Self {
+        Css::new()
+    }
+}
+
+/// Returns the style value for the given [`Length`].
+///
+/// [`Length`]: ../enum.Length.html
+pub fn length(length: Length) -> String {
+    match length {
+        Length::Shrink => String::from("auto"),
+        Length::Units(units) => format!("{}px", units),
+        Length::Fill | Length::FillPortion(_) => String::from("100%"),
+    }
+}
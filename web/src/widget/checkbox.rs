@@ -4,7 +4,22 @@ use crate::{css, Bus, Css, Element, Length, Widget};
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 use dodrio::bumpalo;
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+thread_local! {
+    // rAF callbacks awaiting the first mount of an indeterminate checkbox,
+    // keyed by element id. Kept here so each callback is dropped by
+    // `clear_pending` on a later render that finds the mounted input, instead
+    // of being leaked with `Closure::forget`.
+    static PENDING_INDETERMINATE: RefCell<HashMap<String, Closure<dyn FnMut()>>> =
+        RefCell::new(HashMap::new());
+}
 
 /// A box that can be checked.
 ///
@@ -26,10 +41,14 @@ use std::rc::Rc;
 #[allow(missing_debug_implementations)]
 pub struct Checkbox<Message> {
     is_checked: bool,
+    is_indeterminate: bool,
     on_toggle: Rc<dyn Fn(bool) -> Message>,
+    on_toggle_also: Vec<Rc<dyn Fn(bool) -> Message>>,
     label: String,
+    label_with: Option<Box<dyn Fn(bool) -> String>>,
     id: String,
     width: Length,
+    images: Option<css::Toggler>,
     style: Box<dyn StyleSheet>,
 }
 
@@ -50,10 +69,14 @@ impl<Message> Checkbox<Message> {
     {
         Checkbox {
             is_checked,
+            is_indeterminate: false,
             on_toggle: Rc::new(f),
+            on_toggle_also: Vec::new(),
             label: label.into(),
+            label_with: None,
             id: Default::default(),
             width: Length::Shrink,
+            images: None,
             style: Default::default(),
         }
     }
@@ -66,6 +89,71 @@ impl<Message> Checkbox<Message> {
         self
     }
 
+    /// Registers an additional listener to be notified when the [`Checkbox`]
+    /// is toggled.
+    ///
+    /// Every registered closure produces its own `Message`, and all of them are
+    /// published to the [`Bus`] on a single click. This is handy when a toggle
+    /// must both update local state and trigger a side-effect (analytics,
+    /// validation) without merging them into one `Message` variant.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Bus`]: ../struct.Bus.html
+    pub fn on_toggle_also<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_toggle_also.push(Rc::new(f));
+        self
+    }
+
+    /// Sets a label that depends on whether the [`Checkbox`] is checked.
+    ///
+    /// The closure is evaluated with the current checked state every time the
+    /// [`Checkbox`] is rendered, letting callers annotate the state inline
+    /// (e.g. `"Muted"` / `"Unmuted"`). It takes precedence over the fixed label
+    /// given to [`new`].
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`new`]: #method.new
+    pub fn label_with(
+        mut self,
+        label: impl 'static + Fn(bool) -> String,
+    ) -> Self {
+        self.label_with = Some(Box::new(label));
+        self
+    }
+
+    /// Sets whether the [`Checkbox`] is in the indeterminate (tri-state) mode.
+    ///
+    /// The indeterminate state is purely visual and distinct from `checked`; it
+    /// is typically used for a "select all" parent whose children are only
+    /// partially selected. It is cleared automatically the next time the
+    /// [`Checkbox`] is clicked.
+    ///
+    /// The indeterminate flag is a JS property rather than an attribute, so it
+    /// needs an `id` to be applied to the rendered element; when the caller did
+    /// not set one with [`id`], a stable id derived from the label is used.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`id`]: #method.id
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
+    /// Sets the images used to draw the [`Checkbox`] indicator.
+    ///
+    /// When provided, the native indicator is hidden and replaced by a themed
+    /// `<span>` whose background image swaps between the given URLs based on the
+    /// `:checked` and `:hover` states through a generated CSS rule.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn images(mut self, images: css::Toggler) -> Self {
+        self.images = Some(images);
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -95,46 +183,117 @@ where
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
 
+        let rendered_label = match &self.label_with {
+            Some(label) => label(self.is_checked),
+            None => self.label.clone(),
+        };
+
         let checkbox_label =
-            bumpalo::format!(in bump, "{}", self.label).into_bump_str();
-        let checkbox_id =
-            bumpalo::format!(in bump, "{}", self.id).into_bump_str();
+            bumpalo::format!(in bump, "{}", rendered_label).into_bump_str();
+
+        // The indeterminate property needs a stable id to be applied. Keep the
+        // caller's id, otherwise derive one from the label so it is identical
+        // across renders.
+        let id = if !self.id.is_empty() {
+            self.id.clone()
+        } else if self.is_indeterminate {
+            let mut hasher = DefaultHasher::new();
+            self.label.hash(&mut hasher);
+            format!("iced-checkbox-{:x}", hasher.finish())
+        } else {
+            String::new()
+        };
+
+        let checkbox_id = bumpalo::format!(in bump, "{}", id).into_bump_str();
 
         let event_bus = bus.clone();
         let on_toggle = self.on_toggle.clone();
+        let on_toggle_also = self.on_toggle_also.clone();
         let is_checked = self.is_checked;
 
+        // `indeterminate` is a JS property rather than an attribute, so it is
+        // not expressible through dodrio's builder. Once mounted, the input is
+        // found synchronously and the property is set with no allocation. Only
+        // on the very first render (before mount) do we schedule a single rAF,
+        // stored so it is dropped on a later render that finds the mounted
+        // input rather than leaked.
+        if self.is_indeterminate {
+            if let Some(input) = find_input(&id) {
+                input.set_indeterminate(true);
+
+                // Mounted now, so any first-render callback can be released.
+                clear_pending(&id);
+            } else {
+                schedule_indeterminate(id.clone());
+            }
+        }
+
         let row_class = style_sheet.insert(bump, css::Rule::Row);
 
         let spacing_class = style_sheet.insert(bump, css::Rule::Spacing(5));
 
+        let input_node = input(bump)
+            .attr("type", "checkbox")
+            .attr("id", checkbox_id)
+            .bool_attr("checked", self.is_checked)
+            .on("click", move |_root, vdom, event| {
+                // Clicking always resolves the indeterminate state into a
+                // concrete checked/unchecked value.
+                if let Some(input) = event.target().and_then(|target| {
+                    target.dyn_into::<web_sys::HtmlInputElement>().ok()
+                }) {
+                    input.set_indeterminate(false);
+                }
+
+                event_bus.publish(on_toggle(!is_checked));
+
+                for listener in &on_toggle_also {
+                    event_bus.publish(listener(!is_checked));
+                }
+
+                vdom.schedule_render();
+            })
+            .finish();
+
+        // When images are provided, hide the native indicator and swap a themed
+        // `<span>` background through a rule shared on the `Css` sheet so it is
+        // emitted only once for all checkboxes using the same images.
+        let (class, children) = match &self.images {
+            Some(images) => {
+                let image_class = style_sheet
+                    .insert(bump, css::Rule::Toggler(images.clone()));
+
+                let class = bumpalo::format!(
+                    in bump, "{} {} {}", row_class, spacing_class, image_class
+                )
+                .into_bump_str();
+
+                let children = vec![
+                    input_node,
+                    span(bump).attr("class", "indicator").finish(),
+                    text(checkbox_label),
+                ];
+
+                (class, children)
+            }
+            None => {
+                let class =
+                    bumpalo::format!(in bump, "{} {}", row_class, spacing_class)
+                        .into_bump_str();
+
+                (class, vec![input_node, text(checkbox_label)])
+            }
+        };
+
         label(bump)
             .attr("for", checkbox_id)
-            .attr(
-                "class",
-                bumpalo::format!(in bump, "{} {}", row_class, spacing_class)
-                    .into_bump_str(),
-            )
+            .attr("class", class)
             .attr(
                 "style",
                 bumpalo::format!(in bump, "width: {}; align-items: center", css::length(self.width))
                     .into_bump_str(),
             )
-            .children(vec![
-                // TODO: Checkbox styling
-                input(bump)
-                    .attr("type", "checkbox")
-                    .attr("id", checkbox_id)
-                    .bool_attr("checked", self.is_checked)
-                    .on("click", move |_root, vdom, _event| {
-                        let msg = on_toggle(!is_checked);
-                        event_bus.publish(msg);
-
-                        vdom.schedule_render();
-                    })
-                    .finish(),
-                text(checkbox_label),
-            ])
+            .children(children)
             .finish()
     }
 }
@@ -147,3 +306,49 @@ where
         Element::new(checkbox)
     }
 }
+
+/// Looks up the rendered checkbox input by its `id`.
+fn find_input(id: &str) -> Option<web_sys::HtmlInputElement> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(id))
+        .and_then(|element| element.dyn_into::<web_sys::HtmlInputElement>().ok())
+}
+
+/// Schedules a single `requestAnimationFrame` that sets `indeterminate` on the
+/// input once it is mounted.
+///
+/// At most one callback is kept per `id`, so the steady-state render path does
+/// not allocate, and the stored callback is dropped by [`clear_pending`] on the
+/// next render rather than leaked.
+fn schedule_indeterminate(id: String) {
+    let already = PENDING_INDETERMINATE
+        .with(|pending| pending.borrow().contains_key(&id));
+
+    if already {
+        return;
+    }
+
+    let callback_id = id.clone();
+    let callback = Closure::wrap(Box::new(move || {
+        if let Some(input) = find_input(&callback_id) {
+            input.set_indeterminate(true);
+        }
+    }) as Box<dyn FnMut()>);
+
+    if let Some(window) = web_sys::window() {
+        let _ = window
+            .request_animation_frame(callback.as_ref().unchecked_ref());
+
+        PENDING_INDETERMINATE.with(|pending| {
+            let _ = pending.borrow_mut().insert(id, callback);
+        });
+    }
+}
+
+/// Releases the pending first-render callback for the given `id`, if any.
+fn clear_pending(id: &str) {
+    PENDING_INDETERMINATE.with(|pending| {
+        let _ = pending.borrow_mut().remove(id);
+    });
+}
@@ -1,5 +1,5 @@
 //! Create choices using radio buttons.
-use crate::{Bus, Css, Element, Widget};
+use crate::{css, Bus, Css, Element, Widget};
 
 pub use iced_style::radio::{Style, StyleSheet};
 
@@ -34,9 +34,12 @@ use dodrio::bumpalo;
 pub struct Radio<Message> {
     is_selected: bool,
     on_click: Message,
+    on_click_also: Vec<Message>,
     label: String,
+    label_with: Option<Box<dyn Fn(bool) -> String>>,
     id: String,
     name: String,
+    images: Option<css::Toggler>,
     style: Box<dyn StyleSheet>,
 }
 
@@ -64,13 +67,63 @@ impl<Message> Radio<Message> {
         Radio {
             is_selected: Some(value) == selected,
             on_click: f(value),
+            on_click_also: Vec::new(),
             label: label.into(),
+            label_with: None,
             id: Default::default(),
             name: Default::default(),
+            images: None,
             style: Default::default(),
         }
     }
 
+    /// Registers an additional message to be produced when the [`Radio`] is
+    /// selected.
+    ///
+    /// Like the message produced by the function given to [`new`], it is
+    /// published to the [`Bus`] on click, alongside every other registered
+    /// message. This is
+    /// handy when a selection must both update local state and trigger a
+    /// side-effect (analytics, validation) without merging them into one
+    /// `Message` variant.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`new`]: #method.new
+    /// [`Bus`]: ../struct.Bus.html
+    pub fn on_select_also(mut self, message: Message) -> Self {
+        self.on_click_also.push(message);
+        self
+    }
+
+    /// Sets a label that depends on whether the [`Radio`] is selected.
+    ///
+    /// The closure is evaluated with the current selected state every time the
+    /// [`Radio`] is rendered, letting callers annotate the state inline (e.g.
+    /// `"On"` / `"Off"`). It takes precedence over the fixed label given to
+    /// [`new`].
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`new`]: #method.new
+    pub fn label_with(
+        mut self,
+        label: impl 'static + Fn(bool) -> String,
+    ) -> Self {
+        self.label_with = Some(Box::new(label));
+        self
+    }
+
+    /// Sets the images used to draw the [`Radio`] indicator.
+    ///
+    /// When provided, the native indicator is hidden and replaced by a themed
+    /// `<span>` whose background image swaps between the given URLs based on the
+    /// `:checked` and `:hover` states through a generated CSS rule.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn images(mut self, images: css::Toggler) -> Self {
+        self.images = Some(images);
+        self
+    }
+
     /// Sets the style of the [`Radio`] button.
     ///
     /// [`Radio`]: struct.Radio.html
@@ -104,37 +157,68 @@ where
         &self,
         bump: &'b bumpalo::Bump,
         bus: &Bus<Message>,
-        _style_sheet: &mut Css<'b>,
+        style_sheet: &mut Css<'b>,
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
 
+        let rendered_label = match &self.label_with {
+            Some(label) => label(self.is_selected),
+            None => self.label.clone(),
+        };
+
         let radio_label =
-            bumpalo::format!(in bump, "{}", self.label).into_bump_str();
+            bumpalo::format!(in bump, "{}", rendered_label).into_bump_str();
         let radio_name =
             bumpalo::format!(in bump, "{}", self.name).into_bump_str();
         let radio_id = bumpalo::format!(in bump, "{}", self.id).into_bump_str();
 
         let event_bus = bus.clone();
         let on_click = self.on_click.clone();
+        let on_click_also = self.on_click_also.clone();
 
-        // TODO: Complete styling
-        label(bump)
+        let input_node = input(bump)
+            .attr("type", "radio")
+            .attr("id", radio_id)
+            .attr("name", radio_name)
+            .attr("style", "margin-right: 10px")
+            .bool_attr("checked", self.is_selected)
+            .on("click", move |_root, _vdom, _event| {
+                event_bus.publish(on_click.clone());
+
+                for message in &on_click_also {
+                    event_bus.publish(message.clone());
+                }
+            })
+            .finish();
+
+        // When images are provided, hide the native indicator and swap a themed
+        // `<span>` background through a rule shared on the `Css` sheet so it is
+        // emitted only once for all radios using the same images.
+        let mut node = label(bump)
             .attr("style", "display: block; font-size: 20px")
-            .attr("for", radio_id)
-            .children(vec![
-                input(bump)
-                    .attr("type", "radio")
-                    .attr("id", radio_id)
-                    .attr("name", radio_name)
-                    .attr("style", "margin-right: 10px")
-                    .bool_attr("checked", self.is_selected)
-                    .on("click", move |_root, _vdom, _event| {
-                        event_bus.publish(on_click.clone());
-                    })
-                    .finish(),
-                text(radio_label),
-            ])
-            .finish()
+            .attr("for", radio_id);
+
+        let node = match &self.images {
+            Some(images) => {
+                let image_class = style_sheet
+                    .insert(bump, css::Rule::Toggler(images.clone()));
+
+                node = node.attr(
+                    "class",
+                    bumpalo::format!(in bump, "{}", image_class)
+                        .into_bump_str(),
+                );
+
+                node.children(vec![
+                    input_node,
+                    span(bump).attr("class", "indicator").finish(),
+                    text(radio_label),
+                ])
+            }
+            None => node.children(vec![input_node, text(radio_label)]),
+        };
+
+        node.finish()
     }
 }
 
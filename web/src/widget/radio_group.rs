@@ -0,0 +1,161 @@
+//! Group radio buttons so that only one may be selected at a time.
+use crate::widget::radio::Radio;
+use crate::{css, Bus, Css, Element, Widget};
+
+pub use iced_style::radio::{Style, StyleSheet};
+
+use dodrio::bumpalo;
+use std::rc::Rc;
+use std::sync::atomic::{self, AtomicUsize};
+
+static NEXT_GROUP_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A group of radio buttons of which only a single one can be selected.
+///
+/// Unlike building [`Radio`] buttons by hand, a [`RadioGroup`] owns the whole
+/// set of choices together with the currently selected value. It shares a
+/// single `name` attribute between its children so that browser-native
+/// grouping keeps exactly one option checked, and produces a `Message` when
+/// any of its options is clicked.
+///
+/// # Example
+/// ```
+/// # use iced_web::RadioGroup;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Choice {
+///     A,
+///     B,
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     ChoiceSelected(Choice),
+/// }
+///
+/// let selected = Some(Choice::A);
+///
+/// RadioGroup::new(selected, Message::ChoiceSelected)
+///     .push(Choice::A, "This is A")
+///     .push(Choice::B, "This is B");
+/// ```
+///
+/// [`Radio`]: struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+#[allow(missing_debug_implementations)]
+pub struct RadioGroup<V, Message> {
+    selected: Option<V>,
+    options: Vec<(V, String)>,
+    on_select: Rc<dyn Fn(V) -> Message>,
+    name: String,
+    spacing: u16,
+    style: Box<dyn StyleSheet>,
+}
+
+impl<V, Message> RadioGroup<V, Message> {
+    /// Creates a new [`RadioGroup`] with no options.
+    ///
+    /// It expects:
+    ///   * the currently selected value, if any
+    ///   * a function that will be called when an option is selected. It
+    ///   receives the value of the chosen option and must produce a `Message`.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn new<F>(selected: Option<V>, f: F) -> Self
+    where
+        V: Eq + Copy,
+        F: 'static + Fn(V) -> Message,
+    {
+        let id = NEXT_GROUP_ID.fetch_add(1, atomic::Ordering::Relaxed);
+
+        RadioGroup {
+            selected,
+            options: Vec::new(),
+            on_select: Rc::new(f),
+            name: format!("iced-radio-group-{}", id),
+            spacing: 0,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds an option with the given value and label to the [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn push(mut self, value: V, label: impl Into<String>) -> Self {
+        self.options.push((value, label.into()));
+        self
+    }
+
+    /// Sets the spacing between the options of the [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<V, Message> Widget<Message> for RadioGroup<V, Message>
+where
+    V: 'static + Eq + Copy,
+    Message: 'static + Clone,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let column_class = style_sheet.insert(bump, css::Rule::Column);
+
+        let spacing_class =
+            style_sheet.insert(bump, css::Rule::Spacing(self.spacing));
+
+        let mut options = bumpalo::collections::Vec::new_in(bump);
+
+        for (value, label) in &self.options {
+            let on_select = self.on_select.clone();
+
+            // Render every option as a `Radio` sharing the group's `name`, so
+            // the group inherits its rendering instead of duplicating it.
+            let radio = Radio::new(
+                *value,
+                label.clone(),
+                self.selected,
+                move |value| on_select(value),
+            )
+            .name(self.name.clone());
+
+            options.push(radio.node(bump, bus, style_sheet));
+        }
+
+        div(bump)
+            .attr(
+                "class",
+                bumpalo::format!(in bump, "{} {}", column_class, spacing_class)
+                    .into_bump_str(),
+            )
+            .children(options)
+            .finish()
+    }
+}
+
+impl<'a, V, Message> From<RadioGroup<V, Message>> for Element<'a, Message>
+where
+    V: 'static + Eq + Copy,
+    Message: 'static + Clone,
+{
+    fn from(radio_group: RadioGroup<V, Message>) -> Element<'a, Message> {
+        Element::new(radio_group)
+    }
+}